@@ -5,6 +5,7 @@ use cached::SizedCache;
 use lru::LruCache;
 use parking_lot::Mutex;
 use quick_cache::sync::Cache as QuickCache;
+use quick_cache::Weighter;
 
 pub trait Cache {
     type Item: Clone;
@@ -12,17 +13,15 @@ pub trait Cache {
     fn get_key(&self, key: &usize) -> Option<Self::Item>;
 
     fn set_key(&self, key: usize, value: Self::Item);
-}
-
-impl<T: Clone> Cache for Arc<QuickCache<usize, T>> {
-    type Item = T;
 
-    fn get_key(&self, key: &usize) -> Option<Self::Item> {
-        self.get(key)
-    }
+    fn remove_key(&self, key: &usize) -> Option<Self::Item>;
 
-    fn set_key(&self, key: usize, value: Self::Item) {
-        self.insert(key, value);
+    /// Inserts a key/value pair along with a weight. No backend here reads
+    /// `weight`: the weight-bounded ones (moka, quick_cache) derive it from the
+    /// value through their weighers, and the rest fall back to `set_key`.
+    fn set_key_weighted(&self, key: usize, value: Self::Item, weight: u32) {
+        let _ = weight;
+        self.set_key(key, value);
     }
 }
 
@@ -36,6 +35,10 @@ impl<T: Clone> Cache for Arc<Mutex<LruCache<usize, T>>> {
     fn set_key(&self, key: usize, value: Self::Item) {
         self.lock().put(key, value);
     }
+
+    fn remove_key(&self, key: &usize) -> Option<Self::Item> {
+        self.lock().pop(key)
+    }
 }
 
 impl<T: Clone> Cache for Arc<Mutex<SizedCache<usize, T>>> {
@@ -48,4 +51,61 @@ impl<T: Clone> Cache for Arc<Mutex<SizedCache<usize, T>>> {
     fn set_key(&self, key: usize, value: Self::Item) {
         self.lock().cache_set(key, value);
     }
+
+    fn remove_key(&self, key: &usize) -> Option<Self::Item> {
+        self.lock().cache_remove(key)
+    }
+}
+
+/// Weighs a `String` value by its byte length, so a cache's capacity becomes a
+/// memory budget rather than an item count.
+#[derive(Clone)]
+pub struct ByteLengthWeighter;
+
+impl Weighter<usize, String> for ByteLengthWeighter {
+    fn weight(&self, _key: &usize, value: &String) -> u64 {
+        value.len().max(1) as u64
+    }
+}
+
+impl Cache for Arc<QuickCache<usize, String, ByteLengthWeighter>> {
+    type Item = String;
+
+    fn get_key(&self, key: &usize) -> Option<Self::Item> {
+        self.get(key)
+    }
+
+    fn set_key(&self, key: usize, value: Self::Item) {
+        self.insert(key, value);
+    }
+
+    fn remove_key(&self, key: &usize) -> Option<Self::Item> {
+        self.remove(key).map(|(_, value)| value)
+    }
+
+    fn set_key_weighted(&self, key: usize, value: Self::Item, _weight: u32) {
+        // The weight is already derived from the value by `ByteLengthWeighter`.
+        self.insert(key, value);
+    }
+}
+
+impl Cache for Arc<moka::sync::Cache<usize, String>> {
+    type Item = String;
+
+    fn get_key(&self, key: &usize) -> Option<Self::Item> {
+        self.get(key)
+    }
+
+    fn set_key(&self, key: usize, value: Self::Item) {
+        self.insert(key, value);
+    }
+
+    fn remove_key(&self, key: &usize) -> Option<Self::Item> {
+        self.remove(key)
+    }
+
+    fn set_key_weighted(&self, key: usize, value: Self::Item, _weight: u32) {
+        // The weight is already derived from the value by the builder's weigher.
+        self.insert(key, value);
+    }
 }
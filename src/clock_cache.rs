@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::cache::Cache;
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+/// An in-crate CLOCK (second-chance) cache: a fixed-size circular array of
+/// slots, each carrying a single reference bit, plus a `key -> slot index`
+/// map and a clock hand that sweeps the array on eviction. This gives the
+/// benchmark a reference eviction policy that isn't pulled in from an
+/// external crate.
+pub struct ClockCache<T> {
+    slots: Vec<Option<(usize, T)>>,
+    referenced: Vec<bool>,
+    index: HashMap<usize, usize>,
+    hand: AtomicUsize,
+    capacity: usize,
+    /// Lowest slot index never yet allocated; used to hand out fresh slots in
+    /// O(1) while the cache is still filling up.
+    next_free: usize,
+    /// Slots freed by `remove`, reused before advancing `next_free`.
+    free_list: Vec<usize>,
+}
+
+impl<T: Clone> ClockCache<T> {
+    pub fn new(capacity: usize) -> Self {
+        ClockCache {
+            slots: vec![None; capacity],
+            referenced: vec![false; capacity],
+            index: HashMap::with_capacity(capacity),
+            hand: AtomicUsize::new(0),
+            capacity,
+            next_free: 0,
+            free_list: Vec::new(),
+        }
+    }
+
+    pub fn get(&mut self, key: &usize) -> Option<T> {
+        let slot_idx = *self.index.get(key)?;
+        self.referenced[slot_idx] = true;
+        self.slots[slot_idx].as_ref().map(|(_, value)| value.clone())
+    }
+
+    pub fn insert(&mut self, key: usize, value: T) {
+        if let Some(&slot_idx) = self.index.get(&key) {
+            self.slots[slot_idx] = Some((key, value));
+            self.referenced[slot_idx] = true;
+            return;
+        }
+
+        let slot_idx = if self.index.len() < self.capacity {
+            self.next_free_slot()
+        } else {
+            self.evict_one()
+        };
+
+        self.slots[slot_idx] = Some((key, value));
+        self.referenced[slot_idx] = true;
+        self.index.insert(key, slot_idx);
+    }
+
+    pub fn remove(&mut self, key: &usize) -> Option<T> {
+        let slot_idx = self.index.remove(key)?;
+        self.referenced[slot_idx] = false;
+        let value = self.slots[slot_idx].take().map(|(_, value)| value);
+        self.free_list.push(slot_idx);
+        value
+    }
+
+    /// Hands out a slot in O(1): reuses a slot freed by `remove` if one is
+    /// available, otherwise advances past the never-yet-allocated prefix.
+    fn next_free_slot(&mut self) -> usize {
+        if let Some(slot_idx) = self.free_list.pop() {
+            return slot_idx;
+        }
+        let slot_idx = self.next_free;
+        self.next_free += 1;
+        slot_idx
+    }
+
+    /// Advances the clock hand: each slot it visits gets a second chance if
+    /// its reference bit is set (the bit is cleared and the hand moves on),
+    /// otherwise that slot is evicted and its index becomes available.
+    fn evict_one(&mut self) -> usize {
+        loop {
+            let slot_idx = self.hand.fetch_add(1, Ordering::Relaxed) % self.capacity;
+
+            if self.referenced[slot_idx] {
+                self.referenced[slot_idx] = false;
+                continue;
+            }
+
+            if let Some((old_key, _)) = self.slots[slot_idx].take() {
+                self.index.remove(&old_key);
+            }
+            return slot_idx;
+        }
+    }
+}
+
+impl<T: Clone> Cache for Arc<Mutex<ClockCache<T>>> {
+    type Item = T;
+
+    fn get_key(&self, key: &usize) -> Option<Self::Item> {
+        self.lock().get(key)
+    }
+
+    fn set_key(&self, key: usize, value: Self::Item) {
+        self.lock().insert(key, value);
+    }
+
+    fn remove_key(&self, key: &usize) -> Option<Self::Item> {
+        self.lock().remove(key)
+    }
+}
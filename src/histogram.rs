@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+/// A log-scaled latency histogram in the HdrHistogram style: each power-of-two
+/// range of nanosecond durations is split into `SUB_BUCKETS` linear sub-buckets,
+/// giving roughly constant relative precision without the cost of a full
+/// HdrHistogram dependency.
+pub struct Histogram {
+    buckets: Vec<u64>,
+}
+
+/// Sub-buckets per power of two. Higher values trade memory for precision.
+const SUB_BUCKETS: u64 = 4;
+const NUM_POW2: usize = 64;
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Histogram::new()
+    }
+}
+
+impl Histogram {
+    pub fn new() -> Self {
+        Histogram {
+            buckets: vec![0; NUM_POW2 * SUB_BUCKETS as usize],
+        }
+    }
+
+    /// Records a single observed duration.
+    pub fn record(&mut self, duration: Duration) {
+        let ns = duration.as_nanos().max(1) as u64;
+        self.buckets[Self::bucket_index(ns)] += 1;
+    }
+
+    /// Merges another thread's histogram into this one.
+    pub fn merge(&mut self, other: &Histogram) {
+        for (a, b) in self.buckets.iter_mut().zip(other.buckets.iter()) {
+            *a += b;
+        }
+    }
+
+    /// Returns the upper bound, in nanoseconds, of the bucket containing the
+    /// `p`-th percentile (e.g. `p = 0.99` for p99).
+    pub fn percentile(&self, p: f64) -> u64 {
+        let total: u64 = self.buckets.iter().sum();
+        if total == 0 {
+            return 0;
+        }
+
+        let target = ((total as f64) * p).ceil().max(1.0) as u64;
+        let mut cumulative = 0u64;
+        for (bucket, &count) in self.buckets.iter().enumerate() {
+            cumulative += count;
+            if cumulative >= target {
+                return Self::bucket_upper_bound_ns(bucket);
+            }
+        }
+
+        Self::bucket_upper_bound_ns(self.buckets.len() - 1)
+    }
+
+    /// Returns the upper bound, in nanoseconds, of the highest non-empty bucket.
+    pub fn max_ns(&self) -> u64 {
+        for (bucket, &count) in self.buckets.iter().enumerate().rev() {
+            if count > 0 {
+                return Self::bucket_upper_bound_ns(bucket);
+            }
+        }
+        0
+    }
+
+    fn bucket_index(ns: u64) -> usize {
+        let pow2 = 63 - ns.leading_zeros();
+        let base = 1u64 << pow2;
+        let offset_in_pow2 = ns - base;
+        let sub = (offset_in_pow2 * SUB_BUCKETS) / base;
+        pow2 as usize * SUB_BUCKETS as usize + sub as usize
+    }
+
+    fn bucket_upper_bound_ns(bucket: usize) -> u64 {
+        let pow2 = (bucket / SUB_BUCKETS as usize) as u32;
+        let sub = (bucket % SUB_BUCKETS as usize) as u64;
+        let base = 1u64 << pow2;
+        base + ((sub + 1) * base) / SUB_BUCKETS
+    }
+}
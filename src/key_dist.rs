@@ -0,0 +1,96 @@
+use rand::Rng;
+
+/// Selects how keys are drawn for a benchmark run.
+#[derive(Clone, Copy, Debug)]
+pub enum KeyDistribution {
+    /// Keys are drawn uniformly at random across the key space.
+    Uniform,
+    /// Keys follow a Zipfian distribution with the given skew parameter, concentrating
+    /// accesses on a small hot set — the shape most real-world cache traffic takes.
+    Zipfian { theta: f64 },
+    /// Keys are drawn in increasing order, wrapping around the key space.
+    Sequential,
+}
+
+impl KeyDistribution {
+    /// Parses a distribution selected via CLI/config. `theta` is only consulted
+    /// for `"zipfian"` and defaults to `0.99` if absent or unparsable.
+    pub fn parse(kind: &str, theta: Option<&str>) -> Option<Self> {
+        match kind {
+            "uniform" => Some(KeyDistribution::Uniform),
+            "sequential" => Some(KeyDistribution::Sequential),
+            "zipfian" => {
+                let mut theta = theta.and_then(|t| t.parse().ok()).unwrap_or(0.99);
+                // theta == 1.0 sends ZipfianGenerator's alpha to infinity and its
+                // eta to zero, so every draw past rank 2 collapses onto the last
+                // key. Nudge it off the pole.
+                if theta == 1.0 {
+                    eprintln!("warning: zipfian theta of 1.0 is unsupported, using 0.999 instead");
+                    theta = 0.999;
+                }
+                Some(KeyDistribution::Zipfian { theta })
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Default for KeyDistribution {
+    fn default() -> Self {
+        KeyDistribution::Zipfian { theta: 0.99 }
+    }
+}
+
+/// Jim Gray's rejection-sampling Zipfian generator, as used by the YCSB benchmark.
+///
+/// Precomputes `zeta(n, theta)` and the constants needed to map a uniform draw
+/// in `[0, 1)` onto a rank in `[1, n]` without rebuilding a cumulative
+/// distribution table.
+pub struct ZipfianGenerator {
+    n: u64,
+    theta: f64,
+    alpha: f64,
+    eta: f64,
+    zetan: f64,
+}
+
+impl ZipfianGenerator {
+    pub fn new(n: u64, theta: f64) -> Self {
+        let zetan = Self::zeta(n, theta);
+        let zeta2 = Self::zeta(2, theta);
+        let alpha = 1.0 / (1.0 - theta);
+        let eta = (1.0 - (2.0 / n as f64).powf(1.0 - theta)) / (1.0 - zeta2 / zetan);
+
+        ZipfianGenerator {
+            n,
+            theta,
+            alpha,
+            eta,
+            zetan,
+        }
+    }
+
+    fn zeta(n: u64, theta: f64) -> f64 {
+        (1..=n).map(|i| 1.0 / (i as f64).powf(theta)).sum()
+    }
+
+    /// Maps a uniform draw `u` in `[0, 1)` onto a 1-based rank in `[1, n]`.
+    fn next_rank(&self, u: f64) -> u64 {
+        let uz = u * self.zetan;
+        if uz < 1.0 {
+            return 1;
+        }
+        if uz < 1.0 + 0.5f64.powf(self.theta) {
+            return 2;
+        }
+
+        let rank = 1.0 + self.n as f64 * (self.eta * u - self.eta + 1.0).powf(self.alpha);
+        (rank.floor() as u64).clamp(1, self.n)
+    }
+
+    /// Draws a 0-based key index in `[0, n)`.
+    pub fn sample_key(&self, rng: &mut impl Rng) -> usize {
+        let u: f64 = rng.gen();
+        (self.next_rank(u) - 1) as usize
+    }
+}
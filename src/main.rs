@@ -1,92 +1,156 @@
 use memory_stats::memory_stats;
 mod cache;
+mod clock_cache;
+mod histogram;
+mod key_dist;
 mod result;
+mod stats;
+mod sweep;
+mod workload;
 
-use std::collections::HashSet;
+use std::cell::RefCell;
 use std::num::NonZeroUsize;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
-use cache::Cache;
+use cache::{ByteLengthWeighter, Cache};
 use cached::SizedCache;
+use clock_cache::ClockCache;
+use key_dist::{KeyDistribution, ZipfianGenerator};
 use lru::LruCache;
 use parking_lot::Mutex;
 use quick_cache::sync::Cache as QuickCache;
+use rand::Rng;
 use rayon::prelude::*;
 use result::BenchResult;
+use stats::ThreadStats;
+use sweep::SweepConfig;
 use tabled::Table;
+use thread_local::ThreadLocal;
+use workload::{Op, Workload};
 
-fn bench_cache<C, F, T>(name: &str, cache: C, value_gen: F) -> BenchResult
+#[allow(clippy::too_many_arguments)]
+fn bench_cache<C, F, G, T>(
+    name: &str,
+    cache: C,
+    value_gen: F,
+    weight_gen: G,
+    workload: &Workload,
+    key_distribution: &KeyDistribution,
+    cache_size: usize,
+    num_threads: usize,
+) -> BenchResult
 where
     T: Clone,
     F: Fn(usize) -> T + Send + Sync,
+    G: Fn(usize) -> u32 + Send + Sync,
     C: Cache<Item = T> + Clone + Send + Sync + 'static,
 {
-    let hit_counter = Arc::new(Mutex::new(0u64));
-    let total_ops = NUM_THREADS * OPS_PER_THREAD;
+    let total_ops = workload.total_ops;
+    let ops_per_thread = total_ops / num_threads;
+    let key_space = cache_size * 2;
+    let zipf = match key_distribution {
+        KeyDistribution::Zipfian { theta } => {
+            Some(Arc::new(ZipfianGenerator::new(key_space as u64, *theta)))
+        }
+        _ => None,
+    };
+
+    // Prefill phase: bring the cache up to the workload's target fill level
+    // before the timed phase starts, so the first timed ops aren't all misses.
+    let prefill_count = (cache_size as f64 * workload.prefill_fraction) as usize;
+    (0..prefill_count).into_par_iter().for_each(|key| {
+        cache.set_key_weighted(key, value_gen(key), weight_gen(key));
+    });
 
     // Measure initial memory
     let initial_mem = memory_stats().map(|stats| stats.physical_mem).unwrap_or(0);
     let start = Instant::now();
 
-    let keys = (0..NUM_THREADS)
-        .into_par_iter()
-        .map(|thread_id| {
-            let cache = cache.clone();
-            let mut local_unique_keys = HashSet::new();
-            let hit_counter = Arc::clone(&hit_counter);
-            let mut local_hits = 0;
-
-            for i in 0..OPS_PER_THREAD {
-                // This key generation strategy is designed for testing cache behavior with specific characteristics:
-                //
-                // 1. **Thread Isolation**:
-                // - `thread_id * OPS_PER_THREAD` ensures each thread works on a different range of keys
-                // - Prevents thread contention by giving each thread its own key space
-                //
-                // 2. **Cache Size Testing**:
-                // - `% (CACHE_SIZE * 2)` creates a working set that's twice the cache size
-                // - This ensures some keys will be evicted, testing cache replacement policies // - Creates a mix of cache hits and misses
-                //
-                // Example:
-                //
-                // If OPS_PER_THREAD = 5000 & CACHE_SIZE = 1000 :-
-                // Thread 0: keys 0-4999 % 2000
-                // Thread 1: keys 5000-9999 % 2000
-                // Thread 2: keys 10000-14999 % 2000
-
-                let key = (i + thread_id * OPS_PER_THREAD) % (CACHE_SIZE * 2);
-
-                // Track unique keys
-                local_unique_keys.insert(key);
-
-                if let Some(_) = cache.get_key(&key) {
-                    local_hits += 1;
-                } else {
-                    let value = value_gen(key);
-                    cache.set_key(key, value);
-                }
-            }
+    // Each thread accumulates into its own `ThreadStats` slot, so the hot path
+    // never touches a shared lock; everything is folded together once below.
+    let thread_stats: ThreadLocal<RefCell<ThreadStats>> = ThreadLocal::new();
 
-            let mut hits = hit_counter.lock();
-            *hits += local_hits;
+    (0..num_threads).into_par_iter().for_each(|thread_id| {
+        let cache = cache.clone();
+        let mut rng = rand::thread_rng();
+        let zipf = zipf.clone();
+        let mut local = thread_stats.get_or(|| RefCell::new(ThreadStats::default())).borrow_mut();
 
-            local_unique_keys
-        })
-        .collect::<Vec<_>>();
+        for i in 0..ops_per_thread {
+            // Key generation is pluggable via `KeyDistribution`:
+            //
+            // - `Sequential` starts each thread at a `thread_id * ops_per_thread`
+            //   offset and wraps with `% key_space`, so a working set twice the
+            //   cache size produces a mix of hits and misses. Whenever
+            //   `ops_per_thread` is a multiple of `key_space`, every thread walks
+            //   the same key sequence.
+            // - `Uniform` draws independently at random across the whole key space.
+            // - `Zipfian` concentrates draws on a small hot set via rejection
+            //   sampling, so eviction-policy differences actually show up in the
+            //   hit rate.
+            let key = match key_distribution {
+                KeyDistribution::Sequential => (i + thread_id * ops_per_thread) % key_space,
+                KeyDistribution::Uniform => rng.gen_range(0..key_space),
+                KeyDistribution::Zipfian { .. } => zipf
+                    .as_ref()
+                    .expect("zipf generator built for Zipfian distribution")
+                    .sample_key(&mut rng),
+            };
 
-    let elapsed = start.elapsed();
-    let hits = *hit_counter.lock();
+            // Track unique keys
+            local.unique_keys.insert(key);
 
-    let unique_keys: HashSet<usize> = keys.into_iter().fold(HashSet::new(), |mut acc, keys| {
-        acc.extend(keys);
-        acc
+            let op_start = Instant::now();
+            match workload.sample_op(&mut rng) {
+                Op::Read => {
+                    if cache.get_key(&key).is_some() {
+                        local.hits += 1;
+                    } else {
+                        local.misses += 1;
+                    }
+                }
+                Op::Insert => {
+                    if cache.get_key(&key).is_some() {
+                        local.hits += 1;
+                    } else {
+                        local.misses += 1;
+                        cache.set_key_weighted(key, value_gen(key), weight_gen(key));
+                    }
+                }
+                Op::Update => {
+                    cache.set_key_weighted(key, value_gen(key), weight_gen(key));
+                }
+                Op::Remove => {
+                    cache.remove_key(&key);
+                }
+                Op::Upsert => {
+                    if cache.get_key(&key).is_some() {
+                        local.hits += 1;
+                    } else {
+                        local.misses += 1;
+                    }
+                    // Unlike `Insert`, an upsert always writes, whether or not the key
+                    // was already present.
+                    cache.set_key_weighted(key, value_gen(key), weight_gen(key));
+                }
+            }
+            local.histogram.record(op_start.elapsed());
+        }
     });
-    let total_entries = unique_keys.len();
+
+    let elapsed = start.elapsed();
+
+    let stats = thread_stats
+        .into_iter()
+        .fold(ThreadStats::default(), |mut acc, cell| {
+            acc.merge(cell.into_inner());
+            acc
+        });
+    let total_entries = stats.unique_keys.len();
 
     // Drop unrelated objects to get more accurate memory reading
-    drop(hit_counter);
-    drop(unique_keys);
+    drop(stats.unique_keys);
 
     // Measure final memory
     let final_mem = memory_stats().map(|stats| stats.physical_mem).unwrap_or(0);
@@ -95,46 +159,139 @@ where
     BenchResult {
         total_entries,
         name: name.to_string(),
+        cache_size,
+        num_threads,
         total_time: elapsed.as_millis(),
-        hit_rate: hits as f64 / total_ops as f64,
+        hit_rate: stats.hits as f64 / (stats.hits + stats.misses).max(1) as f64,
         memory_mb: memory_used as f64 / 1024.0 / 1024.0,
         ops_per_sec: total_ops as f64 / elapsed.as_secs_f64(),
+        p50_ns: stats.histogram.percentile(0.50),
+        p99_ns: stats.histogram.percentile(0.99),
+        p999_ns: stats.histogram.percentile(0.999),
+        max_ns: stats.histogram.max_ns(),
     }
 }
 
-const CACHE_SIZE: usize = 10_000;
-const NUM_THREADS: usize = 8;
 const OPS_PER_THREAD: usize = 100_000;
 
-fn main() {
+/// Runs every backend once for a single `(cache_size, num_threads)` sweep point.
+fn run_configuration(config: SweepConfig) -> Vec<BenchResult> {
     let pool = rayon::ThreadPoolBuilder::new()
-        .num_threads(NUM_THREADS)
+        .num_threads(config.num_threads)
         .build()
         .unwrap();
 
-    println!("Running cache benchmarks...");
-    println!("Configuration:");
-    println!("  Cache size: {}", CACHE_SIZE);
-    println!("  Threads: {}", NUM_THREADS);
-    println!("  Operations per thread: {}", OPS_PER_THREAD);
-    println!();
+    println!(
+        "Running cache benchmarks... (cache size: {}, threads: {})",
+        config.cache_size, config.num_threads
+    );
 
     // let json: Value = serde_json::from_str(include_str!("../fixtures/big.json")).unwrap();
     let value = |key: usize| format!("value_{key}");
+    let weight = |key: usize| value(key).len() as u32;
+    let workload = config.workload.build(config.num_threads * OPS_PER_THREAD);
+    let key_distribution = config.key_distribution;
+    let time_to_live = Some(Duration::from_secs(30));
+    let cache_size = config.cache_size;
+    let num_threads = config.num_threads;
 
-    let results = pool.install(|| {
-        let quick_cache = Arc::new(QuickCache::new(CACHE_SIZE));
-        let quick_cache_result = bench_cache("quick_cache", quick_cache, value);
+    // quick_cache and moka bound capacity by weight (bytes), not item count, so
+    // their byte budget is scaled to the average entry size over `cache_size`
+    // keys. Without this, `cache_size` items (lru/cached/clock) and `cache_size`
+    // bytes (quick_cache/moka) would hold wildly different numbers of entries
+    // under the same "Cache Size" label.
+    let avg_entry_weight =
+        ((0..cache_size).map(|key| weight(key) as u64).sum::<u64>() / cache_size.max(1) as u64)
+            .max(1);
+    let weight_capacity = cache_size as u64 * avg_entry_weight;
 
-        let size = NonZeroUsize::new(CACHE_SIZE).unwrap();
+    pool.install(|| {
+        let quick_cache = Arc::new(QuickCache::with_weighter(
+            cache_size,
+            weight_capacity,
+            ByteLengthWeighter,
+        ));
+        let quick_cache_result = bench_cache(
+            "quick_cache",
+            quick_cache,
+            value,
+            weight,
+            &workload,
+            &key_distribution,
+            cache_size,
+            num_threads,
+        );
+
+        let size = NonZeroUsize::new(cache_size).unwrap();
         let lru_cache = Arc::new(Mutex::new(LruCache::new(size)));
-        let lru_cache_result = bench_cache("lru", lru_cache, value);
+        let lru_cache_result = bench_cache(
+            "lru",
+            lru_cache,
+            value,
+            weight,
+            &workload,
+            &key_distribution,
+            cache_size,
+            num_threads,
+        );
 
-        let cached = Arc::new(Mutex::new(SizedCache::with_size(CACHE_SIZE)));
-        let cached_result = bench_cache("cached", cached, value);
+        let cached = Arc::new(Mutex::new(SizedCache::with_size(cache_size)));
+        let cached_result = bench_cache(
+            "cached",
+            cached,
+            value,
+            weight,
+            &workload,
+            &key_distribution,
+            cache_size,
+            num_threads,
+        );
 
-        vec![quick_cache_result, lru_cache_result, cached_result]
-    });
+        let mut moka_builder = moka::sync::Cache::builder()
+            .max_capacity(weight_capacity)
+            .weigher(|_key: &usize, value: &String| value.len() as u32);
+        if let Some(ttl) = time_to_live {
+            moka_builder = moka_builder.time_to_live(ttl);
+        }
+        let moka_cache = Arc::new(moka_builder.build());
+        let moka_result = bench_cache(
+            "moka",
+            moka_cache,
+            value,
+            weight,
+            &workload,
+            &key_distribution,
+            cache_size,
+            num_threads,
+        );
+
+        let clock_cache = Arc::new(Mutex::new(ClockCache::new(cache_size)));
+        let clock_cache_result = bench_cache(
+            "clock",
+            clock_cache,
+            value,
+            weight,
+            &workload,
+            &key_distribution,
+            cache_size,
+            num_threads,
+        );
+
+        vec![
+            quick_cache_result,
+            lru_cache_result,
+            cached_result,
+            moka_result,
+            clock_cache_result,
+        ]
+    })
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let sweep = SweepConfig::from_args(&args);
+
+    let results: Vec<BenchResult> = sweep.into_iter().flat_map(run_configuration).collect();
 
     println!("Results:");
 
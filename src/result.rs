@@ -4,6 +4,12 @@ use tabled::Tabled;
 pub struct BenchResult {
     #[tabled(rename = "Type")]
     pub name: String,
+    /// The sweep configuration's cache size, so a scaling curve can be read off the table.
+    #[tabled(rename = "Cache Size")]
+    pub cache_size: usize,
+    /// The sweep configuration's thread count, so a scaling curve can be read off the table.
+    #[tabled(rename = "Threads")]
+    pub num_threads: usize,
     #[tabled(rename = "Hit Rate", format("{:.2}", self.hit_rate * 100.0))]
     pub hit_rate: f64,
     #[tabled(rename = "Ops/sec", format("{:.3}", self.ops_per_sec ))]
@@ -17,4 +23,17 @@ pub struct BenchResult {
     /// The amount of memory used, in megabytes, by cache at the end of the operations.
     #[tabled(rename = "Memory (MB)", format("{:.2}", self.memory_mb))]
     pub memory_mb: f64,
+    /// Median per-operation latency, in nanoseconds.
+    #[tabled(rename = "p50 (ns)")]
+    pub p50_ns: u64,
+    /// 99th-percentile per-operation latency, in nanoseconds.
+    #[tabled(rename = "p99 (ns)")]
+    pub p99_ns: u64,
+    /// 99.9th-percentile per-operation latency, in nanoseconds.
+    #[tabled(rename = "p999 (ns)")]
+    pub p999_ns: u64,
+    /// Worst observed per-operation latency, in nanoseconds, rounded up to the
+    /// upper bound of its histogram bucket.
+    #[tabled(rename = "Max (ns)")]
+    pub max_ns: u64,
 }
@@ -0,0 +1,25 @@
+use std::collections::HashSet;
+
+use crate::histogram::Histogram;
+
+/// Per-thread accumulator for `bench_cache`'s timed phase. One of these lives
+/// in each thread's slot of a `thread_local::ThreadLocal`, so hits, misses,
+/// per-op latencies and unique keys are all recorded without touching a
+/// shared lock on the hot path; `bench_cache` folds every thread's `ThreadStats`
+/// into one once the parallel region finishes.
+#[derive(Default)]
+pub struct ThreadStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub unique_keys: HashSet<usize>,
+    pub histogram: Histogram,
+}
+
+impl ThreadStats {
+    pub fn merge(&mut self, other: ThreadStats) {
+        self.hits += other.hits;
+        self.misses += other.misses;
+        self.unique_keys.extend(other.unique_keys);
+        self.histogram.merge(&other.histogram);
+    }
+}
@@ -0,0 +1,97 @@
+use crate::key_dist::KeyDistribution;
+use crate::workload::WorkloadKind;
+
+/// One `(cache_size, num_threads, workload, key_distribution)` point in a
+/// scaling-sweep run.
+#[derive(Clone, Copy, Debug)]
+pub struct SweepConfig {
+    pub cache_size: usize,
+    pub num_threads: usize,
+    pub workload: WorkloadKind,
+    pub key_distribution: KeyDistribution,
+}
+
+impl SweepConfig {
+    /// Parses sweep points from CLI args of the form
+    /// `cache_size:num_threads[:workload[:key_distribution[:theta]]]`
+    /// (e.g. `1000:4`, `1000:4:write`, or `1000:4:read:zipfian:1.2`).
+    /// `workload` is one of `read`, `write` or `churn`; `key_distribution` is one of
+    /// `uniform`, `sequential` or `zipfian` (`theta` only applies to `zipfian`).
+    /// Any omitted or unrecognized segment falls back to its default. `cache_size` and
+    /// `num_threads` must both be positive — non-positive values would panic on the
+    /// divide-by-zero in `bench_cache` or the `NonZeroUsize` construction in
+    /// `run_configuration`, so such args are rejected with a warning instead. Falls
+    /// back to [`SweepConfig::default_sweep`] if none of the args parse, so the
+    /// benchmark still runs something useful without flags.
+    pub fn from_args(args: &[String]) -> Vec<SweepConfig> {
+        let configs: Vec<SweepConfig> = args
+            .iter()
+            .filter_map(|arg| {
+                let parts: Vec<&str> = arg.split(':').collect();
+                let cache_size: usize = parts.first()?.parse().ok()?;
+                let num_threads: usize = parts.get(1)?.parse().ok()?;
+                if cache_size == 0 || num_threads == 0 {
+                    eprintln!(
+                        "warning: skipping sweep point \"{arg}\" — cache_size and num_threads must both be positive"
+                    );
+                    return None;
+                }
+                let workload = parts
+                    .get(2)
+                    .and_then(|s| WorkloadKind::parse(s))
+                    .unwrap_or_default();
+                let key_distribution = parts
+                    .get(3)
+                    .and_then(|kind| KeyDistribution::parse(kind, parts.get(4).copied()))
+                    .unwrap_or_default();
+                Some(SweepConfig {
+                    cache_size,
+                    num_threads,
+                    workload,
+                    key_distribution,
+                })
+            })
+            .collect();
+
+        if configs.is_empty() {
+            Self::default_sweep()
+        } else {
+            configs
+        }
+    }
+
+    fn default_sweep() -> Vec<SweepConfig> {
+        vec![
+            SweepConfig {
+                cache_size: 1_000,
+                num_threads: 2,
+                workload: WorkloadKind::ReadHeavy,
+                key_distribution: KeyDistribution::Uniform,
+            },
+            SweepConfig {
+                cache_size: 1_000,
+                num_threads: 8,
+                workload: WorkloadKind::WriteHeavy,
+                key_distribution: KeyDistribution::Sequential,
+            },
+            SweepConfig {
+                cache_size: 10_000,
+                num_threads: 2,
+                workload: WorkloadKind::Churn,
+                key_distribution: KeyDistribution::Zipfian { theta: 0.99 },
+            },
+            SweepConfig {
+                cache_size: 10_000,
+                num_threads: 8,
+                workload: WorkloadKind::ReadHeavy,
+                key_distribution: KeyDistribution::Zipfian { theta: 0.99 },
+            },
+            SweepConfig {
+                cache_size: 50_000,
+                num_threads: 8,
+                workload: WorkloadKind::WriteHeavy,
+                key_distribution: KeyDistribution::Uniform,
+            },
+        ]
+    }
+}
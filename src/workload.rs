@@ -0,0 +1,133 @@
+use rand::Rng;
+
+/// The operation drawn for a single timed iteration, as selected by [`Workload::sample_op`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Op {
+    Read,
+    Insert,
+    Update,
+    Remove,
+    Upsert,
+}
+
+/// Relative proportions of each operation kind exercised during the timed phase,
+/// modeled after the libcuckoo Universal Benchmark mix that `bustle` ports.
+///
+/// `read`, `insert`, `update`, `remove` and `upsert` are expected to sum to `1.0`;
+/// each iteration draws a uniform float and maps it onto whichever operation's
+/// cumulative share it lands in (see [`Workload::sample_op`]).
+#[derive(Clone, Copy, Debug)]
+pub struct Workload {
+    pub read: f64,
+    pub insert: f64,
+    pub update: f64,
+    pub remove: f64,
+    pub upsert: f64,
+    /// Fraction of the run's cache size to populate before the timed phase begins.
+    pub prefill_fraction: f64,
+    /// Total number of operations executed across all threads during the timed phase.
+    pub total_ops: usize,
+}
+
+impl Workload {
+    /// Mostly lookups with a light trickle of writes, e.g. bustle's `read_heavy`.
+    pub fn read_heavy(total_ops: usize) -> Self {
+        Workload {
+            read: 0.95,
+            insert: 0.03,
+            update: 0.02,
+            remove: 0.0,
+            upsert: 0.0,
+            prefill_fraction: 0.75,
+            total_ops,
+        }
+    }
+
+    /// Inserts and updates dominate, with reads used mainly to drive hit rate.
+    pub fn write_heavy(total_ops: usize) -> Self {
+        Workload {
+            read: 0.3,
+            insert: 0.4,
+            update: 0.2,
+            remove: 0.1,
+            upsert: 0.0,
+            prefill_fraction: 0.25,
+            total_ops,
+        }
+    }
+
+    /// Heavy insert/remove/upsert traffic against a mostly-empty cache, to stress eviction.
+    pub fn churn(total_ops: usize) -> Self {
+        Workload {
+            read: 0.2,
+            insert: 0.2,
+            update: 0.1,
+            remove: 0.2,
+            upsert: 0.3,
+            prefill_fraction: 0.1,
+            total_ops,
+        }
+    }
+
+    /// Draws the next operation kind from a uniform sample in `[0, 1)`.
+    pub fn sample_op(&self, rng: &mut impl Rng) -> Op {
+        let u: f64 = rng.gen();
+
+        let mut acc = self.read;
+        if u < acc {
+            return Op::Read;
+        }
+        acc += self.insert;
+        if u < acc {
+            return Op::Insert;
+        }
+        acc += self.update;
+        if u < acc {
+            return Op::Update;
+        }
+        acc += self.remove;
+        if u < acc {
+            return Op::Remove;
+        }
+        acc += self.upsert;
+        if u < acc {
+            return Op::Upsert;
+        }
+        // Proportions should sum to 1.0; fall back to Upsert for any float slop.
+        Op::Upsert
+    }
+}
+
+/// Named workload preset selectable via CLI/config, so `write_heavy` and
+/// `churn` are reachable by users and not just by callers in this crate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WorkloadKind {
+    ReadHeavy,
+    WriteHeavy,
+    Churn,
+}
+
+impl WorkloadKind {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "read" | "read_heavy" => Some(WorkloadKind::ReadHeavy),
+            "write" | "write_heavy" => Some(WorkloadKind::WriteHeavy),
+            "churn" => Some(WorkloadKind::Churn),
+            _ => None,
+        }
+    }
+
+    pub fn build(self, total_ops: usize) -> Workload {
+        match self {
+            WorkloadKind::ReadHeavy => Workload::read_heavy(total_ops),
+            WorkloadKind::WriteHeavy => Workload::write_heavy(total_ops),
+            WorkloadKind::Churn => Workload::churn(total_ops),
+        }
+    }
+}
+
+impl Default for WorkloadKind {
+    fn default() -> Self {
+        WorkloadKind::ReadHeavy
+    }
+}